@@ -0,0 +1,275 @@
+import { createSignal, Index, onCleanup, onMount, Show } from "solid-js";
+import type {
+	CopilotAccount,
+	CopilotConfig,
+	CopilotStatus,
+} from "../lib/tauri";
+import {
+	captureCopilotGithubToken,
+	getConfig,
+	getCopilotAccountsStatus,
+	onCopilotStatusChanged,
+	saveConfig,
+	startCopilot,
+	stopCopilot,
+} from "../lib/tauri";
+import { toastStore } from "../stores/toast";
+import { Button } from "./ui";
+import { Switch } from "./ui/Switch";
+
+interface CopilotAccountsProps {
+	config: CopilotConfig;
+	onConfigChange: (config: CopilotConfig) => void;
+	proxyRunning: boolean;
+}
+
+// Additional Copilot seats, each served by its own copilot-api instance
+export function CopilotAccounts(props: CopilotAccountsProps) {
+	const [accounts, setAccounts] = createSignal<CopilotAccount[]>(
+		props.config.accounts ?? [],
+	);
+	const [statuses, setStatuses] = createSignal<Record<string, CopilotStatus>>(
+		{},
+	);
+	const [saving, setSaving] = createSignal(false);
+	const [busy, setBusy] = createSignal<string | null>(null);
+
+	onMount(async () => {
+		try {
+			const list = await getCopilotAccountsStatus();
+			setStatuses(Object.fromEntries(list.map((s) => [s.accountId, s])));
+		} catch (err) {
+			console.error("Failed to get Copilot account status:", err);
+		}
+
+		const unlisten = await onCopilotStatusChanged((status) => {
+			setStatuses((prev) => ({ ...prev, [status.accountId]: status }));
+		});
+		onCleanup(() => unlisten());
+	});
+
+	const saved = (id: string) =>
+		(props.config.accounts ?? []).some((account) => account.id === id);
+	const dirty = () =>
+		JSON.stringify(accounts()) !== JSON.stringify(props.config.accounts ?? []);
+
+	const update = (id: string, changes: Partial<CopilotAccount>) => {
+		setAccounts((prev) =>
+			prev.map((account) =>
+				account.id === id ? { ...account, ...changes } : account,
+			),
+		);
+	};
+
+	const addAccount = () => {
+		const usedPorts = [
+			props.config.port,
+			...accounts().map((account) => account.port),
+		];
+		setAccounts((prev) => [
+			...prev,
+			{
+				id: crypto.randomUUID(),
+				name: "",
+				enabled: true,
+				port: Math.max(...usedPorts) + 1,
+				accountType: "individual",
+				githubToken: "",
+				rateLimitWait: false,
+			},
+		]);
+	};
+
+	const removeAccount = (id: string) => {
+		setAccounts((prev) => prev.filter((account) => account.id !== id));
+	};
+
+	// Saving stops the instances of disabled or removed accounts
+	const handleSave = async () => {
+		setSaving(true);
+		try {
+			const copilot = { ...props.config, accounts: accounts() };
+			const fullConfig = await getConfig();
+			await saveConfig({ ...fullConfig, copilot });
+			props.onConfigChange(copilot);
+			toastStore.success("Copilot accounts saved");
+		} catch (err) {
+			console.error("Failed to save Copilot accounts:", err);
+			toastStore.error("Failed to save Copilot accounts", String(err));
+		} finally {
+			setSaving(false);
+		}
+	};
+
+	const label = (account: CopilotAccount) => account.name.trim() || account.id;
+
+	const handleStartStop = async (account: CopilotAccount) => {
+		setBusy(account.id);
+		try {
+			if (statuses()[account.id]?.running) {
+				await stopCopilot(account.id);
+			} else {
+				await startCopilot(account.id);
+			}
+		} catch (err) {
+			toastStore.error(`Copilot account "${label(account)}"`, String(err));
+		} finally {
+			setBusy(null);
+		}
+	};
+
+	// Use the token copilot-api saved after the last device login on this machine
+	const handleCaptureToken = async (account: CopilotAccount) => {
+		setBusy(account.id);
+		try {
+			const check = await captureCopilotGithubToken(account.id);
+			const fullConfig = await getConfig();
+			props.onConfigChange(fullConfig.copilot);
+			setAccounts(fullConfig.copilot.accounts ?? []);
+			toastStore.success("GitHub token saved", `Signed in as ${check.login}`);
+		} catch (err) {
+			toastStore.error("Failed to capture GitHub token", String(err));
+		} finally {
+			setBusy(null);
+		}
+	};
+
+	const statusText = (account: CopilotAccount) => {
+		const status = statuses()[account.id];
+		if (!saved(account.id)) return "Not saved";
+		if (!status) return "Offline";
+		if (status.crashLoop && !status.running) return "Crashed repeatedly";
+		if (status.auth.state === "awaitingUserCode")
+			return `Enter ${status.auth.code} at ${status.auth.verificationUri}`;
+		if (status.auth.state === "failed") return status.auth.reason;
+		if (status.auth.state === "expired") return "Device code expired";
+		if (status.running) return status.authenticated ? "Connected" : "Running";
+		return "Offline";
+	};
+
+	const inputClass =
+		"w-full px-2 py-1 text-sm rounded-lg border border-gray-200 dark:border-gray-600 bg-white dark:bg-gray-700 text-gray-900 dark:text-gray-100";
+
+	return (
+		<div class="space-y-2">
+			<div>
+				<label class="block text-xs font-medium text-gray-700 dark:text-gray-300">
+					Additional Accounts
+				</label>
+				<p class="text-xs text-gray-500 dark:text-gray-400">
+					Extra Copilot seats, each on its own port. A GitHub token is required
+					because copilot-api keeps one device login per machine.
+				</p>
+			</div>
+
+			<Index each={accounts()}>
+				{(account) => (
+					<div class="p-2 rounded-lg border border-gray-200 dark:border-gray-700 space-y-2">
+						<div class="flex items-center gap-2">
+							<input
+								type="text"
+								placeholder="Name"
+								value={account().name}
+								onInput={(e) =>
+									update(account().id, { name: e.currentTarget.value })
+								}
+								class={inputClass}
+							/>
+							<input
+								type="number"
+								title="Port"
+								value={account().port}
+								onInput={(e) =>
+									update(account().id, {
+										port: parseInt(e.currentTarget.value) || account().port,
+									})
+								}
+								class="w-24 px-2 py-1 text-sm rounded-lg border border-gray-200 dark:border-gray-600 bg-white dark:bg-gray-700 text-gray-900 dark:text-gray-100"
+							/>
+							<Switch
+								checked={account().enabled}
+								onChange={(enabled) => update(account().id, { enabled })}
+							/>
+						</div>
+						<div class="flex items-center gap-2">
+							<select
+								value={account().accountType}
+								onChange={(e) =>
+									update(account().id, { accountType: e.currentTarget.value })
+								}
+								class={inputClass}
+							>
+								<option value="individual">Individual</option>
+								<option value="business">Business</option>
+								<option value="enterprise">Enterprise</option>
+							</select>
+							<input
+								type="password"
+								placeholder="GitHub token"
+								value={account().githubToken}
+								onInput={(e) =>
+									update(account().id, { githubToken: e.currentTarget.value })
+								}
+								class={inputClass}
+							/>
+						</div>
+						<div class="flex items-center justify-between gap-2">
+							<span class="text-xs text-gray-500 dark:text-gray-400 truncate">
+								{statusText(account())}
+							</span>
+							<div class="flex items-center gap-1 flex-shrink-0">
+								<Show when={saved(account().id)}>
+									<Button
+										size="sm"
+										variant="ghost"
+										onClick={() => handleCaptureToken(account())}
+										disabled={busy() !== null || dirty()}
+										title="Use the token from the last copilot-api device login"
+									>
+										Use login token
+									</Button>
+									<Button
+										size="sm"
+										variant="secondary"
+										onClick={() => handleStartStop(account())}
+										disabled={
+											busy() !== null ||
+											!account().enabled ||
+											!props.config.enabled ||
+											(!props.proxyRunning && !statuses()[account().id]?.running)
+										}
+									>
+										{statuses()[account().id]?.running ? "Stop" : "Start"}
+									</Button>
+								</Show>
+								<Button
+									size="sm"
+									variant="danger"
+									onClick={() => removeAccount(account().id)}
+								>
+									Remove
+								</Button>
+							</div>
+						</div>
+					</div>
+				)}
+			</Index>
+
+			<div class="flex items-center gap-2">
+				<Button size="sm" variant="secondary" onClick={addAccount}>
+					Add Account
+				</Button>
+				<Show when={dirty()}>
+					<Button
+						size="sm"
+						variant="primary"
+						onClick={handleSave}
+						disabled={saving()}
+					>
+						{saving() ? "Saving..." : "Save Accounts"}
+					</Button>
+				</Show>
+			</div>
+		</div>
+	);
+}
@@ -1,4 +1,3 @@
-import { openUrl } from "@tauri-apps/plugin-opener";
 import { createSignal, onCleanup, onMount, Show } from "solid-js";
 import type {
 	CopilotApiDetection,
@@ -6,17 +5,20 @@ import type {
 	CopilotStatus,
 } from "../lib/tauri";
 import {
+	COPILOT_PRIMARY_ACCOUNT,
 	checkCopilotHealth,
 	detectCopilotApi,
 	getConfig,
 	getCopilotStatus,
 	onCopilotAuthRequired,
 	onCopilotStatusChanged,
+	openCopilotVerification,
 	saveConfig,
 	startCopilot,
 	stopCopilot,
 } from "../lib/tauri";
 import { toastStore } from "../stores/toast";
+import { CopilotAccounts } from "./CopilotAccounts";
 import { Button } from "./ui";
 import { Switch } from "./ui/Switch";
 
@@ -28,14 +30,17 @@ interface CopilotCardProps {
 
 export function CopilotCard(props: CopilotCardProps) {
 	const [status, setStatus] = createSignal<CopilotStatus>({
+		accountId: COPILOT_PRIMARY_ACCOUNT,
 		running: false,
 		port: 4141,
 		endpoint: "http://localhost:4141",
 		authenticated: false,
+		auth: { state: "notStarted" },
+		restartCount: 0,
+		crashLoop: false,
 	});
 	const [starting, setStarting] = createSignal(false);
 	const [stopping, setStopping] = createSignal(false);
-	const [authMessage, setAuthMessage] = createSignal<string | null>(null);
 	const [startError, setStartError] = createSignal<string | null>(null);
 	const [expanded, setExpanded] = createSignal(false);
 	const [apiDetection, setApiDetection] =
@@ -64,22 +69,20 @@ export function CopilotCard(props: CopilotCardProps) {
 			console.error("Failed to detect copilot-api:", err);
 		}
 
-		// Subscribe to status changes
+		// Subscribe to status changes (this card shows the primary account)
 		const unlistenStatus = await onCopilotStatusChanged((newStatus) => {
-			setStatus(newStatus);
+			if (newStatus.accountId === COPILOT_PRIMARY_ACCOUNT) {
+				setStatus(newStatus);
+			}
 		});
 
-		// Subscribe to auth required events
-		const unlistenAuth = await onCopilotAuthRequired((message) => {
-			setAuthMessage(message);
-			// Extract the URL from the message if present
-			const urlMatch = message.match(/https:\/\/github\.com\/login\/device/);
-			if (urlMatch) {
-				toastStore.info(
-					"GitHub Authentication Required",
-					"Check the terminal for your device code, then visit github.com/login/device",
-				);
-			}
+		// Subscribe to auth required events (additional accounts show their own codes)
+		const unlistenAuth = await onCopilotAuthRequired((deviceCode) => {
+			if (deviceCode.accountId !== COPILOT_PRIMARY_ACCOUNT) return;
+			toastStore.info(
+				"GitHub Authentication Required",
+				`Enter code ${deviceCode.code} at ${deviceCode.verificationUri}`,
+			);
 		});
 
 		// Poll for health status when running but not authenticated
@@ -127,7 +130,6 @@ export function CopilotCard(props: CopilotCardProps) {
 	const handleStart = async () => {
 		if (starting() || status().running) return;
 		setStarting(true);
-		setAuthMessage(null);
 		setStartError(null);
 
 		try {
@@ -171,9 +173,14 @@ export function CopilotCard(props: CopilotCardProps) {
 		}
 	};
 
+	// Open the verification page and copy the code so it can be pasted right away
 	const handleOpenGitHubAuth = async () => {
 		try {
-			await openUrl("https://github.com/login/device");
+			const deviceCode = await openCopilotVerification();
+			await navigator.clipboard
+				.writeText(deviceCode.code)
+				.then(() => toastStore.success("Đã copy!", deviceCode.code))
+				.catch(() => {});
 		} catch (err) {
 			console.error("Failed to open GitHub auth URL:", err);
 			// Fallback: hiển thị URL để user copy thủ công
@@ -191,22 +198,15 @@ export function CopilotCard(props: CopilotCardProps) {
 	// Parse device code từ auth message
 	// Parse device code from auth message
 	const getDeviceCode = () => {
-		const msg = authMessage();
-		if (!msg) return null;
-		// Các pattern phổ biến từ copilot-api:
-		// Common patterns from copilot-api:
-		// - code "XXXX-XXXX" (có dấu ngoặc kép)
-		// - code: XXXX-XXXX
-		// - XXXX-XXXX (standalone)
-		const patterns = [
-			/code\s*["']([A-Z0-9]{4}-[A-Z0-9]{4})["']/i,  // code "XXXX-XXXX"
-			/code[:\s]+([A-Z0-9]{4}-[A-Z0-9]{4})/i,       // code: XXXX-XXXX
-			/([A-Z0-9]{4}-[A-Z0-9]{4})/i,                 // standalone XXXX-XXXX
-		];
-		for (const pattern of patterns) {
-			const match = msg.match(pattern);
-			if (match) return match[1].toUpperCase();
-		}
+		const auth = status().auth;
+		return auth?.state === "awaitingUserCode" ? auth.code : null;
+	};
+
+	// Device code expired or the flow failed: restarting copilot-api asks for a new code
+	const authProblem = () => {
+		const auth = status().auth;
+		if (auth?.state === "expired") return "The GitHub device code expired.";
+		if (auth?.state === "failed") return auth.reason;
 		return null;
 	};
 
@@ -282,7 +282,7 @@ export function CopilotCard(props: CopilotCardProps) {
 			<Show when={props.config.enabled}>
 				<div class="p-4 space-y-4">
 					{/* Auth message */}
-					<Show when={authMessage() && !status().authenticated}>
+					<Show when={status().auth?.state === "awaitingUserCode"}>
 						<div class="p-3 rounded-lg bg-amber-50 dark:bg-amber-900/20 border border-amber-200 dark:border-amber-800">
 							<div class="flex items-start gap-3">
 								<svg
@@ -353,6 +353,35 @@ export function CopilotCard(props: CopilotCardProps) {
 						</div>
 					</Show>
 
+					{/* Device code expired / auth failed */}
+					<Show when={authProblem()}>
+						<div class="p-3 rounded-lg bg-red-50 dark:bg-red-900/20 border border-red-200 dark:border-red-800">
+							<p class="text-sm font-medium text-red-800 dark:text-red-200">
+								GitHub Authentication Failed
+							</p>
+							<p class="text-xs text-red-700 dark:text-red-300 mt-1">
+								{authProblem()}
+							</p>
+							<p class="text-xs text-red-600 dark:text-red-400 mt-2">
+								Stop and start Copilot again to get a new device code.
+							</p>
+						</div>
+					</Show>
+
+					{/* Supervisor gave up restarting */}
+					<Show when={status().crashLoop && !status().running}>
+						<div class="p-3 rounded-lg bg-red-50 dark:bg-red-900/20 border border-red-200 dark:border-red-800">
+							<p class="text-sm font-medium text-red-800 dark:text-red-200">
+								copilot-api keeps crashing
+							</p>
+							<p class="text-xs text-red-700 dark:text-red-300 mt-1">
+								Automatic restarts stopped after {status().restartCount}{" "}
+								attempt(s). Check the copilot-api logs, then start Copilot
+								again.
+							</p>
+						</div>
+					</Show>
+
 					{/* Start error message */}
 					<Show when={startError() && !status().running}>
 						<div class="p-3 rounded-lg bg-red-50 dark:bg-red-900/20 border border-red-200 dark:border-red-800">
@@ -619,6 +648,13 @@ export function CopilotCard(props: CopilotCardProps) {
 									}
 								/>
 							</div>
+							<div class="pt-3 border-t border-gray-100 dark:border-gray-700">
+								<CopilotAccounts
+									config={props.config}
+									onConfigChange={props.onConfigChange}
+									proxyRunning={props.proxyRunning}
+								/>
+							</div>
 						</div>
 					</Show>
 				</div>
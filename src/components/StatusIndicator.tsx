@@ -2,6 +2,7 @@ interface StatusIndicatorProps {
   running: boolean;
   onToggle: () => void;
   disabled?: boolean;
+  label?: string; // Replaces "Running"/"Stopped", e.g. while stopping
 }
 
 export function StatusIndicator(props: StatusIndicatorProps) {
@@ -26,7 +27,7 @@ export function StatusIndicator(props: StatusIndicatorProps) {
         ></span>
       </span>
       <span class="text-sm font-medium">
-        {props.running ? "Running" : "Stopped"}
+        {props.label ?? (props.running ? "Running" : "Stopped")}
       </span>
     </button>
   );
@@ -10,10 +10,130 @@ export async function stopProxy(): Promise<ProxyStatus> {
 	return invoke("stop_proxy");
 }
 
+export type ConfigApplyResult =
+	| "notRunning"
+	| "unchanged"
+	| "hotReloaded"
+	| "restarted";
+
+// Apply the saved config to the running proxy (hot reload, restart only if needed)
+export async function applyProxyConfig(): Promise<ConfigApplyResult> {
+	return invoke("apply_proxy_config");
+}
+
 export interface ProxyStatus {
 	running: boolean;
 	port: number;
 	endpoint: string;
+	restartCount: number; // Automatic restarts since the last manual start
+	lastExitCode: number | null;
+	crashLoop: boolean; // Supervisor stopped restarting after repeated crashes
+	draining: boolean; // Stopping: waiting for in-flight requests to finish
+}
+
+export interface ProxyCrash {
+	timestamp: number;
+	exitCode: number | null;
+	signal: number | null;
+	stderr: string[];
+}
+
+export async function getProxyCrashes(): Promise<ProxyCrash[]> {
+	return invoke("get_proxy_crashes");
+}
+
+export interface ProxyStartupError {
+	message: string;
+	exitCode: number | null;
+	signal: number | null;
+	timedOut: boolean;
+	stderr: string[]; // Last proxy stderr lines
+}
+
+// Why the last startProxy failed (also emitted as "proxy-startup-failed")
+export async function getProxyStartupError(): Promise<ProxyStartupError | null> {
+	return invoke("get_proxy_startup_error");
+}
+
+// copilot-api-<account id> for additional Copilot accounts
+export type SidecarProcess = "cliproxyapi" | "copilot-api" | `copilot-api-${string}`;
+
+export interface ProcessOutputLine {
+	seq: number; // Pass as `since` to only get newer lines
+	process: SidecarProcess;
+	stream: "stdout" | "stderr" | "system";
+	timestamp: number;
+	line: string;
+}
+
+// Recent sidecar output; new lines are also emitted as "process-output"
+export async function getProcessOutput(
+	process: SidecarProcess,
+	since?: number,
+): Promise<ProcessOutputLine[]> {
+	return invoke("get_process_output", { process, since });
+}
+
+export async function onProcessOutput(
+	callback: (line: ProcessOutputLine) => void,
+): Promise<UnlistenFn> {
+	return listen<ProcessOutputLine>("process-output", (event) => {
+		callback(event.payload);
+	});
+}
+
+export interface PortChange {
+	service: "proxy" | "copilot" | `copilot:${string}`; // copilot:<account id> for additional accounts
+	oldPort: number;
+	newPort: number;
+	updatedFiles: string[]; // Agent config files pointed at the new endpoint
+}
+
+// Emitted after the backend changed the saved config (profile switch, key rotation,
+// bundle import, port switch); reload it with getConfig
+export async function onConfigChanged(callback: () => void): Promise<UnlistenFn> {
+	return listen("config-changed", () => {
+		callback();
+	});
+}
+
+// Emitted when a busy port was replaced by the next free one (autoSelectPort)
+export async function onPortChanged(
+	callback: (change: PortChange) => void,
+): Promise<UnlistenFn> {
+	return listen<PortChange>("port-changed", (event) => {
+		callback(event.payload);
+	});
+}
+
+export interface ProxyDrainProgress {
+	inFlight: number | null; // Open client connections (null = could not be counted)
+	remainingSecs: number;
+}
+
+export async function onProxyDrainProgress(
+	callback: (progress: ProxyDrainProgress) => void,
+): Promise<UnlistenFn> {
+	return listen<ProxyDrainProgress>("proxy-drain-progress", (event) => {
+		callback(event.payload);
+	});
+}
+
+export interface ManagementCapability {
+	endpoint: string;
+	minVersion: string;
+	supported: boolean;
+}
+
+export interface SidecarInfo {
+	version: string | null; // null = not detected (all endpoints assumed supported)
+	binary: string; // "bundled" or the custom binary path
+	capabilities: ManagementCapability[];
+}
+
+// Version and Management API capabilities of the running proxy
+export async function getSidecarInfo(): Promise<SidecarInfo | null> {
+	return invoke("get_sidecar_info");
 }
 
 export async function getProxyStatus(): Promise<ProxyStatus> {
@@ -208,37 +328,67 @@ export const COPILOT_MODELS = {
 	],
 };
 
-// OpenAI-compatible model for Amp routing
+// OpenAI-compatible model for Amp routing (provider form)
 export interface AmpOpenAIModel {
 	name: string;
 	alias: string;
 }
 
-// OpenAI-compatible provider configuration for Amp
-export interface AmpOpenAIProvider {
-	id: string; // Unique identifier (UUID)
-	name: string;
-	baseUrl: string;
-	apiKey: string;
-	models: AmpOpenAIModel[];
-}
-
 // GitHub Copilot configuration (via copilot-api)
 export interface CopilotConfig {
 	enabled: boolean;
 	port: number;
 	accountType: string; // "individual", "business", "enterprise"
-	githubToken: string;
+	githubToken: string; // Passed to copilot-api as --github-token (skips the device flow; visible in the process list)
+	rateLimit?: number;
+	rateLimitWait: boolean;
+	accounts?: CopilotAccount[]; // Additional seats, each run as its own copilot-api instance
+}
+
+// Id of the account described by the top-level CopilotConfig fields
+export const COPILOT_PRIMARY_ACCOUNT = "default";
+
+export interface CopilotAccount {
+	id: string;
+	name: string;
+	enabled: boolean;
+	port: number; // Must differ from the proxy and other accounts
+	accountType: string;
+	githubToken: string; // Required: copilot-api keeps one device-login token per machine
 	rateLimit?: number;
 	rateLimitWait: boolean;
 }
 
 // Copilot status
 export interface CopilotStatus {
+	accountId: string;
 	running: boolean;
 	port: number;
 	endpoint: string;
-	authenticated: boolean;
+	authenticated: boolean; // Same as auth.state === "authenticated"
+	auth: CopilotAuthState;
+	restartCount: number; // Automatic restarts since the last manual start
+	crashLoop: boolean; // Gave up restarting after repeated crashes
+}
+
+// GitHub device-code flow as reported by copilot-api
+export type CopilotAuthState =
+	| { state: "notStarted" }
+	| {
+			state: "awaitingUserCode";
+			code: string;
+			verificationUri: string;
+			expiresAt: number; // Unix ms
+	  }
+	| { state: "authenticated" }
+	| { state: "expired" }
+	| { state: "failed"; reason: string };
+
+export interface CopilotDeviceCode {
+	accountId: string;
+	code: string;
+	verificationUri: string;
+	expiresAt: number;
 }
 
 // Copilot API detection result
@@ -276,11 +426,16 @@ export interface AppConfig {
 	loggingToFile: boolean;
 	ampApiKey: string;
 	ampModelMappings: AmpModelMapping[];
-	ampOpenaiProvider?: AmpOpenAIProvider; // Deprecated: for migration only
-	ampOpenaiProviders: AmpOpenAIProvider[]; // Array of custom providers
+	openaiCompatibleProviders: OpenAICompatibleProvider[]; // Custom OpenAI-compatible providers
 	ampRoutingMode: string; // "mappings" or "openai"
 	copilot: CopilotConfig;
 	forceModelMappings: boolean; // Force model mappings to take precedence over local API keys
+	payloadRules?: PayloadRule[]; // User-defined payload injection rules
+	proxyStartupTimeoutSecs?: number; // How long startProxy waits for the proxy to become ready
+	processOutputLogFile?: boolean; // Mirror sidecar output to logs/<process>.log
+	autoSelectPort?: boolean; // Use the next free port when the configured one is taken
+	proxyDrainTimeoutSecs?: number; // How long stopProxy waits for in-flight requests (0 = kill now)
+	cliproxyapiBinaryPath?: string; // Run this cliproxyapi binary instead of the bundled one
 	// Server Mode: Allow remote clients to connect
 	serverMode: boolean;
 	bindAddress: string; // "localhost" or "0.0.0.0"
@@ -291,10 +446,38 @@ export async function getConfig(): Promise<AppConfig> {
 	return invoke("get_config");
 }
 
+// Set when the saved config could not be loaded and was moved aside
+export interface ConfigLoadError {
+	message: string;
+	quarantinePath?: string;
+	vaultLocked: boolean; // Proxy and Copilot refuse to start until unlockSecretVault succeeds
+	saveBlocked: boolean; // Original config.json kept in place; settings changes are not saved
+}
+
+export async function getConfigLoadError(): Promise<ConfigLoadError | null> {
+	return invoke("get_config_load_error");
+}
+
 export async function saveConfig(config: AppConfig): Promise<void> {
 	return invoke("save_config", { config });
 }
 
+export type DiagnosticSeverity = "error" | "warning";
+
+export interface ConfigDiagnostic {
+	severity: DiagnosticSeverity;
+	field: string;
+	message: string;
+	suggestion?: string;
+}
+
+// Validate a config before saving (defaults to the saved config)
+export async function validateConfig(
+	config?: AppConfig,
+): Promise<ConfigDiagnostic[]> {
+	return invoke("validate_config", { config: config ?? null });
+}
+
 // Event listeners
 export interface OAuthCallback {
 	provider: Provider;
@@ -341,16 +524,41 @@ export async function getCopilotStatus(): Promise<CopilotStatus> {
 	return invoke("get_copilot_status");
 }
 
-export async function startCopilot(): Promise<CopilotStatus> {
-	return invoke("start_copilot");
+// Without accountId every configured account is started/stopped (primary status returned)
+export async function startCopilot(accountId?: string): Promise<CopilotStatus> {
+	return invoke("start_copilot", { accountId });
+}
+
+export async function stopCopilot(accountId?: string): Promise<CopilotStatus> {
+	return invoke("stop_copilot", { accountId });
 }
 
-export async function stopCopilot(): Promise<CopilotStatus> {
-	return invoke("stop_copilot");
+export async function getCopilotAccountsStatus(): Promise<CopilotStatus[]> {
+	return invoke("get_copilot_accounts_status");
 }
 
-export async function checkCopilotHealth(): Promise<CopilotStatus> {
-	return invoke("check_copilot_health");
+export async function checkCopilotHealth(
+	accountId?: string,
+): Promise<CopilotStatus> {
+	return invoke("check_copilot_health", { accountId });
+}
+
+// Copilot models used for the proxy config (empty = built-in fallback list)
+export async function getCopilotModels(): Promise<string[]> {
+	return invoke("get_copilot_models");
+}
+
+// Re-query copilot-api /v1/models; the proxy config is refreshed if the set changed
+export async function refreshCopilotModels(): Promise<string[]> {
+	return invoke("refresh_copilot_models");
+}
+
+export async function onCopilotModelsChanged(
+	callback: (models: string[]) => void,
+): Promise<UnlistenFn> {
+	return listen<string[]>("copilot-models-changed", (event) => {
+		callback(event.payload);
+	});
 }
 
 export async function detectCopilotApi(): Promise<CopilotApiDetection> {
@@ -370,13 +578,41 @@ export async function onCopilotStatusChanged(
 }
 
 export async function onCopilotAuthRequired(
-	callback: (message: string) => void,
+	callback: (deviceCode: CopilotDeviceCode) => void,
 ): Promise<UnlistenFn> {
-	return listen<string>("copilot-auth-required", (event) => {
+	return listen<CopilotDeviceCode>("copilot-auth-required", (event) => {
 		callback(event.payload);
 	});
 }
 
+// Open the GitHub verification page; returns the code to copy
+export async function openCopilotVerification(
+	accountId?: string,
+): Promise<CopilotDeviceCode> {
+	return invoke("open_copilot_verification", { accountId });
+}
+
+export interface GithubTokenCheck {
+	login: string;
+	scopes: string[]; // Empty for fine-grained tokens
+	maskedToken: string;
+}
+
+// Check a GitHub token (or the configured one) before handing it to copilot-api
+export async function validateCopilotGithubToken(
+	token?: string,
+): Promise<GithubTokenCheck> {
+	return invoke("validate_copilot_github_token", { token });
+}
+
+// Save the token copilot-api stored after a device login into the config
+// (into the given additional account instead of the primary one)
+export async function captureCopilotGithubToken(
+	accountId?: string,
+): Promise<GithubTokenCheck> {
+	return invoke("capture_copilot_github_token", { accountId });
+}
+
 // System notifications
 import {
 	isPermissionGranted,
@@ -626,6 +862,7 @@ export interface GeminiApiKey {
 	baseUrl?: string;
 	proxyUrl?: string;
 	headers?: Record<string, string>;
+	models?: ModelMapping[];
 	excludedModels?: string[];
 }
 
@@ -645,10 +882,13 @@ export interface CodexApiKey {
 	baseUrl?: string;
 	proxyUrl?: string;
 	headers?: Record<string, string>;
+	models?: ModelMapping[];
+	excludedModels?: string[];
 }
 
 // OpenAI-Compatible Provider structure
 export interface OpenAICompatibleProvider {
+	id?: string; // Unique identifier (UUID), assigned by the backend if missing
 	name: string;
 	baseUrl: string;
 	apiKeyEntries: Array<{
@@ -772,6 +1012,177 @@ export async function setReasoningEffortSettings(
 	return invoke("set_reasoning_effort_settings", { settings });
 }
 
+// ============================================
+// Payload Injection Rules
+// ============================================
+
+export type PayloadRuleMode = "default" | "override";
+
+export interface PayloadRule {
+	id: string;
+	name: string;
+	enabled: boolean;
+	models: string[]; // Model names, "*" wildcards allowed (e.g. "gpt-5*")
+	protocol: string; // "", "openai", "claude", "gemini", "codex"
+	params: Record<string, unknown>; // e.g. { "thinking.budget_tokens": 8192 }
+	mode: PayloadRuleMode; // "default" fills missing params, "override" always replaces
+}
+
+export interface PayloadPreset {
+	id: string;
+	name: string;
+	description: string;
+	rules: PayloadRule[];
+}
+
+export async function getPayloadRules(): Promise<PayloadRule[]> {
+	return invoke("get_payload_rules");
+}
+
+export async function getPayloadPresets(): Promise<PayloadPreset[]> {
+	return invoke("get_payload_presets");
+}
+
+export async function setPayloadRules(rules: PayloadRule[]): Promise<void> {
+	return invoke("set_payload_rules", { rules });
+}
+
+// ============================================
+// Configuration Profiles
+// ============================================
+
+export interface ConfigProfile {
+	id: string;
+	name: string;
+	ampModelMappings: AmpModelMapping[];
+	forceModelMappings: boolean;
+	claudeApiKeys: ClaudeApiKey[];
+	geminiApiKeys: GeminiApiKey[];
+	codexApiKeys: CodexApiKey[];
+	openaiCompatibleProviders: OpenAICompatibleProvider[];
+	thinkingBudgetMode: string;
+	thinkingBudgetCustom: number;
+	reasoningEffortLevel: string;
+	payloadRules: PayloadRule[];
+	copilotEnabled: boolean;
+}
+
+export interface ProfileList {
+	profiles: ConfigProfile[];
+	activeProfileId?: string;
+}
+
+export async function listProfiles(): Promise<ProfileList> {
+	return invoke("list_profiles");
+}
+
+// Create a profile from the current settings
+export async function createProfile(name: string): Promise<ConfigProfile> {
+	return invoke("create_profile", { name });
+}
+
+export async function cloneProfile(
+	profileId: string,
+	name: string,
+): Promise<ConfigProfile> {
+	return invoke("clone_profile", { profileId, name });
+}
+
+export async function deleteProfile(profileId: string): Promise<void> {
+	return invoke("delete_profile", { profileId });
+}
+
+export async function activateProfile(profileId: string): Promise<ProfileList> {
+	return invoke("activate_profile", { profileId });
+}
+
+export async function onProfileChanged(
+	callback: (list: ProfileList) => void,
+): Promise<UnlistenFn> {
+	return listen<ProfileList>("profile-changed", (event) => {
+		callback(event.payload);
+	});
+}
+
+// ============================================
+// Export / Import Bundle
+// ============================================
+
+export type BundleSection = "config" | "authFiles" | "history" | "agentConfigs";
+export type ImportMode = "skip" | "merge" | "replace";
+
+export interface BundleManifest {
+	format: string;
+	version: number;
+	appVersion: string;
+	createdAt: string;
+	redacted: boolean;
+	sections: BundleSection[];
+}
+
+export interface ExportBundleOptions {
+	includeAuthFiles?: boolean;
+	includeHistory?: boolean;
+	includeAgentConfigs?: boolean;
+	redactSecrets?: boolean;
+}
+
+export interface ImportBundleOptions {
+	dryRun?: boolean;
+	config?: ImportMode;
+	authFiles?: ImportMode;
+	history?: ImportMode;
+	agentConfigs?: ImportMode;
+}
+
+export interface BundleChange {
+	section: BundleSection;
+	item: string;
+	entry?: string; // Provider name, masked API key, mapping source… of config list fields
+	action: "add" | "update" | "remove";
+}
+
+export interface BundleImportResult {
+	manifest: BundleManifest;
+	applied: boolean;
+	changes: BundleChange[];
+	warnings: string[];
+}
+
+export async function exportBundle(
+	path: string,
+	options: ExportBundleOptions,
+): Promise<BundleManifest> {
+	return invoke("export_bundle", { path, options });
+}
+
+export async function importBundle(
+	path: string,
+	options: ImportBundleOptions,
+): Promise<BundleImportResult> {
+	return invoke("import_bundle", { path, options });
+}
+
+// ============================================
+// Import standalone CLIProxyAPI config.yaml
+// ============================================
+
+export interface UnmappedSection {
+	section: string;
+	reason: string;
+}
+
+export interface CliProxyImportResult {
+	imported: string[];
+	unmapped: UnmappedSection[];
+}
+
+export async function importCliproxyapiConfig(
+	path: string,
+): Promise<CliProxyImportResult> {
+	return invoke("import_cliproxyapi_config", { path });
+}
+
 // ============================================
 // Close to Tray Setting
 // ============================================
@@ -804,9 +1215,10 @@ export async function addOpenAICompatibleProvider(
 }
 
 export async function deleteOpenAICompatibleProvider(
-	index: number,
+	name: string,
+	baseUrl: string,
 ): Promise<void> {
-	return invoke("delete_openai_compatible_provider", { index });
+	return invoke("delete_openai_compatible_provider", { name, baseUrl });
 }
 
 // ============================================
@@ -968,6 +1380,40 @@ export async function setConfigYaml(yaml: string): Promise<void> {
 	return invoke("set_config_yaml", { yaml });
 }
 
+// Generated config merged with proxy-config.user.yaml, with the origin of each key
+export type ConfigKeyOrigin = "generated" | "user" | "removed";
+
+export interface ConfigKeySource {
+	path: string;
+	origin: ConfigKeyOrigin;
+}
+
+export interface EffectiveConfig {
+	yaml: string;
+	sources: ConfigKeySource[];
+	warnings: string[]; // e.g. overlay overriding API key sections managed by ProxyPal
+}
+
+export async function getEffectiveConfigYaml(): Promise<EffectiveConfig> {
+	return invoke("get_effective_config_yaml");
+}
+
+// Dry-run render of the proxy config (secrets masked) with unified diffs
+export interface ProxyConfigPreview {
+	yaml: string;
+	diagnostics: ConfigDiagnostic[];
+	diskDiff: string | null; // null: no proxy-config.yaml yet; "": identical
+	liveDiff: string | null; // null: proxy not running or unreachable
+	liveError: string | null;
+	requiresRestart: boolean;
+}
+
+export async function previewProxyConfig(
+	config?: AppConfig,
+): Promise<ProxyConfigPreview> {
+	return invoke("preview_proxy_config", { config: config ?? null });
+}
+
 // Request Error Logs - view error-specific logs
 export async function getRequestErrorLogs(): Promise<string[]> {
 	return invoke("get_request_error_logs");
@@ -1086,3 +1532,45 @@ export async function isUpdaterSupported(): Promise<UpdaterSupport> {
 export async function getLocalIpAddresses(): Promise<string[]> {
 	return invoke<string[]>("get_local_ip_addresses");
 }
+
+// ============================================
+// Secret Storage
+// ============================================
+
+export type VaultKeySource = "passphrase" | "keyring" | "machine";
+
+export interface SecretStorageInfo {
+	keySource?: VaultKeySource; // Unset until the vault has been created
+	vaultPath: string;
+	secretCount: number;
+	error?: string; // e.g. vault locked (missing PROXYPAL_VAULT_PASSPHRASE)
+}
+
+export async function getSecretStorageInfo(): Promise<SecretStorageInfo> {
+	return invoke("get_secret_storage_info");
+}
+
+// Retry unlocking a vault that failed to open on startup
+export async function unlockSecretVault(passphrase?: string): Promise<void> {
+	return invoke("unlock_secret_vault", { passphrase: passphrase ?? null });
+}
+
+// ============================================
+// Per-install Keys
+// ============================================
+
+export interface KeyRotationResult {
+	localApiKey: string;
+	updatedFiles: string[]; // Agent config files rewritten with the new key
+	proxy: ConfigApplyResult;
+}
+
+// Local API key agents use to authenticate with the proxy
+export async function getLocalApiKey(): Promise<string> {
+	return invoke("get_local_api_key");
+}
+
+// Regenerate the management secret and local API key, updating proxy and agent configs
+export async function rotateProxyKeys(): Promise<KeyRotationResult> {
+	return invoke("rotate_proxy_keys");
+}
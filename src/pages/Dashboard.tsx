@@ -22,9 +22,11 @@ import {
 	getRequestHistory,
 	getUsageStats,
 	importVertexCredential,
+	onProxyDrainProgress,
 	onRequestLog,
 	openOAuth,
 	type Provider,
+	type ProxyDrainProgress,
 	pollOAuthStatus,
 	type RequestHistory,
 	refreshAuthStatus,
@@ -345,6 +347,8 @@ export function DashboardPage() {
 		setCurrentPage,
 	} = appStore;
 	const [toggling, setToggling] = createSignal(false);
+	const [drainProgress, setDrainProgress] =
+		createSignal<ProxyDrainProgress | null>(null);
 	const [connecting, setConnecting] = createSignal<Provider | null>(null);
 	const [recentlyConnected, setRecentlyConnected] = createSignal<Set<Provider>>(
 		new Set(),
@@ -463,12 +467,27 @@ export function DashboardPage() {
 			}, 1000);
 		});
 
+		// Show how many requests the proxy is still finishing while it stops
+		const unlistenDrain = await onProxyDrainProgress((progress) => {
+			setDrainProgress(progress);
+		});
+
 		// Cleanup listener on unmount
 		onCleanup(() => {
 			unlisten();
+			unlistenDrain();
 		});
 	});
 
+	const stopLabel = () => {
+		if (!proxyStatus().draining) return undefined;
+		const progress = drainProgress();
+		if (!progress) return "Stopping...";
+		return progress.inFlight === null
+			? `Stopping... ${progress.remainingSecs}s`
+			: `Finishing ${progress.inFlight} request(s)... ${progress.remainingSecs}s`;
+	};
+
 	// Setup complete when: proxy running + provider connected + agent configured
 	const isSetupComplete = () =>
 		proxyStatus().running && hasAnyProvider() && hasConfiguredAgent();
@@ -481,6 +500,7 @@ export function DashboardPage() {
 		try {
 			if (proxyStatus().running) {
 				const status = await stopProxy();
+				setDrainProgress(null);
 				setProxyStatus(status);
 				toastStore.info("Proxy stopped");
 			} else {
@@ -839,6 +859,7 @@ export function DashboardPage() {
 							running={proxyStatus().running}
 							onToggle={toggleProxy}
 							disabled={toggling()}
+							label={stopLabel()}
 						/>
 						<Button
 							variant="ghost"
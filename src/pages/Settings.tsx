@@ -3,7 +3,7 @@ import { Button, Switch } from "../components/ui";
 import type {
 	AmpModelMapping,
 	AmpOpenAIModel,
-	AmpOpenAIProvider,
+	OpenAICompatibleProvider,
 	ProviderTestResult,
 } from "../lib/tauri";
 import {
@@ -529,13 +529,18 @@ export function SettingsPage() {
 	};
 
 	// Initialize OpenAI provider form for editing
-	const openProviderModal = (provider?: AmpOpenAIProvider) => {
+	const openProviderModal = (provider?: OpenAICompatibleProvider) => {
 		if (provider) {
-			setEditingProviderId(provider.id);
+			setEditingProviderId(provider.id ?? null);
 			setProviderName(provider.name);
 			setProviderBaseUrl(provider.baseUrl);
-			setProviderApiKey(provider.apiKey);
-			setProviderModels(provider.models || []);
+			setProviderApiKey(provider.apiKeyEntries[0]?.apiKey ?? "");
+			setProviderModels(
+				(provider.models || []).map((m) => ({
+					name: m.name,
+					alias: m.alias ?? "",
+				})),
+			);
 		} else {
 			setEditingProviderId(null);
 			setProviderName("");
@@ -738,7 +743,7 @@ export function SettingsPage() {
 		const customModels: { value: string; label: string }[] = [];
 
 		// Add models from all custom OpenAI providers
-		const providers = config().ampOpenaiProviders || [];
+		const providers = config().openaiCompatibleProviders || [];
 		for (const provider of providers) {
 			if (provider?.models) {
 				for (const model of provider.models) {
@@ -840,30 +845,43 @@ export function SettingsPage() {
 			return;
 		}
 
-		const currentProviders = config().ampOpenaiProviders || [];
+		const currentProviders = config().openaiCompatibleProviders || [];
 		const editId = editingProviderId();
+		const models = providerModels().map((m) => ({
+			name: m.name,
+			alias: m.alias || undefined,
+		}));
 
-		let newProviders: AmpOpenAIProvider[];
+		let newProviders: OpenAICompatibleProvider[];
 		if (editId) {
-			// Update existing provider
+			// Update existing provider, keeping additional key entries and headers
 			newProviders = currentProviders.map((p) =>
 				p.id === editId
-					? { id: editId, name, baseUrl, apiKey, models: providerModels() }
+					? {
+							...p,
+							name,
+							baseUrl,
+							apiKeyEntries: [
+								{ ...p.apiKeyEntries[0], apiKey },
+								...p.apiKeyEntries.slice(1),
+							],
+							models,
+						}
 					: p,
 			);
 		} else {
 			// Add new provider with generated UUID
-			const newProvider: AmpOpenAIProvider = {
+			const newProvider: OpenAICompatibleProvider = {
 				id: crypto.randomUUID(),
 				name,
 				baseUrl,
-				apiKey,
-				models: providerModels(),
+				apiKeyEntries: [{ apiKey }],
+				models,
 			};
 			newProviders = [...currentProviders, newProvider];
 		}
 
-		const newConfig = { ...config(), ampOpenaiProviders: newProviders };
+		const newConfig = { ...config(), openaiCompatibleProviders: newProviders };
 		setConfig(newConfig);
 
 		setSaving(true);
@@ -879,11 +897,11 @@ export function SettingsPage() {
 		}
 	};
 
-	const deleteOpenAIProvider = async (providerId: string) => {
-		const currentProviders = config().ampOpenaiProviders || [];
+	const deleteOpenAIProvider = async (providerId: string | undefined) => {
+		const currentProviders = config().openaiCompatibleProviders || [];
 		const newProviders = currentProviders.filter((p) => p.id !== providerId);
 
-		const newConfig = { ...config(), ampOpenaiProviders: newProviders };
+		const newConfig = { ...config(), openaiCompatibleProviders: newProviders };
 		setConfig(newConfig);
 
 		setSaving(true);
@@ -1026,6 +1044,15 @@ export function SettingsPage() {
 								</p>
 							</label>
 
+							<Switch
+								label="Pick a free port automatically"
+								description="If the proxy or Copilot port is taken, use the next free one and update configured agents"
+								checked={config().autoSelectPort ?? false}
+								onChange={(checked) =>
+									handleConfigChange("autoSelectPort", checked)
+								}
+							/>
+
 							<div class="border-t border-gray-200 dark:border-gray-700" />
 
 							<label class="block">
@@ -1872,9 +1899,9 @@ export function SettingsPage() {
 										</p>
 									</div>
 									<div class="flex items-center gap-2">
-										<Show when={(config().ampOpenaiProviders || []).length > 0}>
+										<Show when={(config().openaiCompatibleProviders || []).length > 0}>
 											<span class="text-xs bg-brand-100 dark:bg-brand-900/30 text-brand-700 dark:text-brand-300 px-2 py-0.5 rounded-full">
-												{(config().ampOpenaiProviders || []).length}
+												{(config().openaiCompatibleProviders || []).length}
 											</span>
 										</Show>
 										<svg
@@ -1895,7 +1922,7 @@ export function SettingsPage() {
 
 								<Show when={customProvidersExpanded()}>
 									{/* Provider Table */}
-									<Show when={(config().ampOpenaiProviders || []).length > 0}>
+									<Show when={(config().openaiCompatibleProviders || []).length > 0}>
 										<div class="overflow-x-auto">
 											<table class="w-full text-sm">
 												<thead>
@@ -1907,7 +1934,7 @@ export function SettingsPage() {
 													</tr>
 												</thead>
 												<tbody class="divide-y divide-gray-100 dark:divide-gray-800">
-													<For each={config().ampOpenaiProviders || []}>
+													<For each={config().openaiCompatibleProviders || []}>
 														{(provider) => (
 															<tr class="hover:bg-gray-50 dark:hover:bg-gray-800/50">
 																<td class="py-2 pr-2">
@@ -1988,7 +2015,7 @@ export function SettingsPage() {
 									</Show>
 
 									{/* Empty state */}
-									<Show when={(config().ampOpenaiProviders || []).length === 0}>
+									<Show when={(config().openaiCompatibleProviders || []).length === 0}>
 										<div class="text-center py-6 text-gray-400 dark:text-gray-500 text-sm">
 											No custom providers configured
 										</div>
@@ -9,9 +9,12 @@ import {
 	completeOAuth,
 	getAuthStatus,
 	getConfig,
+	getConfigLoadError,
 	getProxyStatus,
 	onAuthStatusChanged,
+	onConfigChanged,
 	onOAuthCallback,
+	onPortChanged,
 	onProxyStatusChanged,
 	onTrayToggleProxy,
 	refreshAuthStatus,
@@ -19,6 +22,7 @@ import {
 	startProxy,
 	stopProxy,
 } from "../lib/tauri";
+import { toastStore } from "./toast";
 
 function createAppStore() {
 	// Proxy state
@@ -26,6 +30,10 @@ function createAppStore() {
 		running: false,
 		port: 8317,
 		endpoint: "http://localhost:8317/v1",
+		restartCount: 0,
+		lastExitCode: null,
+		crashLoop: false,
+		draining: false,
 	});
 
 	// Auth state
@@ -54,8 +62,7 @@ function createAppStore() {
 		loggingToFile: true, // Bật mặc định để Log Viewer hoạt động
 		ampApiKey: "",
 		ampModelMappings: [],
-		ampOpenaiProvider: undefined,
-		ampOpenaiProviders: [],
+		openaiCompatibleProviders: [],
 		ampRoutingMode: "mappings",
 		forceModelMappings: false,
 		copilot: {
@@ -121,6 +128,19 @@ function createAppStore() {
 			updateProxyStatus(proxyState);
 			setConfig(configState);
 
+			// Saved settings were unusable and set aside - tell the user where they went
+			const configLoadError = await getConfigLoadError();
+			if (configLoadError) {
+				toastStore.error(
+					configLoadError.vaultLocked
+						? "Secret vault is locked"
+						: "Settings could not be loaded",
+					configLoadError.quarantinePath
+						? `${configLoadError.message} The original file was saved to ${configLoadError.quarantinePath}`
+						: configLoadError.message,
+				);
+			}
+
 			// Refresh auth status from CLIProxyAPI's auth directory
 			try {
 				const authState = await refreshAuthStatus();
@@ -193,6 +213,24 @@ function createAppStore() {
 				}
 			});
 
+			// Reload the config after backend-side changes, so a later save from the
+			// Settings page doesn't write back stale values
+			const unlistenConfig = await onConfigChanged(async () => {
+				try {
+					setConfig(await getConfig());
+				} catch (error) {
+					console.error("Failed to reload config:", error);
+				}
+			});
+
+			// The config-changed event brings in the new port
+			const unlistenPort = await onPortChanged((change) => {
+				toastStore.info(
+					`Port ${change.oldPort} was busy`,
+					`Using port ${change.newPort} instead`,
+				);
+			});
+
 			// Auto-start proxy if configured
 			if (configState.autoStart) {
 				try {
@@ -211,6 +249,8 @@ function createAppStore() {
 				unlistenAuth();
 				unlistenOAuth();
 				unlistenTray();
+				unlistenConfig();
+				unlistenPort();
 			});
 		} catch (error) {
 			console.error("Failed to initialize app:", error);